@@ -0,0 +1,169 @@
+use super::HeuristicElement;
+use crate::neighbors::Neighborhood;
+use crate::path::Path;
+use crate::path_cache::MovementConstraints;
+use crate::Point;
+
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/// The direction of the last step taken, as the `(dx, dy)` delta between two adjacent Points.
+type Dir = (i64, i64);
+
+/// A search state: the current Point, the direction arrived from (`None` at `start`), and how
+/// many consecutive Tiles have been covered in that direction.
+type State = (Point, Option<Dir>, usize);
+
+fn direction(from: Point, to: Point) -> Dir {
+    (
+        to.0 as i64 - from.0 as i64,
+        to.1 as i64 - from.1 as i64,
+    )
+}
+
+fn is_reverse(a: Dir, b: Dir) -> bool {
+    a.0 == -b.0 && a.1 == -b.1
+}
+
+/// Variant of [`a_star_search`](super::a_star_search) whose state is augmented with the incoming
+/// direction and the consecutive straight-line run length, so that `constraints` can forbid
+/// turning too early or running too long; see
+/// [`PathCacheConfig::movement_constraints`](crate::path_cache::PathCacheConfig::movement_constraints).
+///
+/// Reversing directly into the direction just come from is never allowed, regardless of
+/// `run_length`, and `goal` is only accepted once at least `constraints.min_run` Tiles have been
+/// covered in a straight line, same as any other Point along the way.
+///
+/// The heuristic stays the plain positional `neighborhood.heuristic`; only the cost of each step
+/// (plus `constraints.turn_cost` whenever the direction changes) is affected.
+pub(crate) fn constrained_a_star_search<N: Neighborhood>(
+    neighborhood: &N,
+    mut get_cost: impl FnMut(Point) -> isize,
+    start: Point,
+    goal: Point,
+    constraints: MovementConstraints,
+    size_hint: usize,
+) -> Option<Path<Point>> {
+    let mut open: BinaryHeap<HeuristicElement<State>> = BinaryHeap::new();
+    let mut came_from: HashMap<State, State> = HashMap::with_capacity(size_hint);
+    let mut g_score: HashMap<State, usize> = HashMap::with_capacity(size_hint);
+    let mut closed: HashSet<State> = HashSet::with_capacity(size_hint);
+    let mut neighbors = Vec::new();
+
+    let start_state: State = (start, None, 0);
+    g_score.insert(start_state, 0);
+    open.push(HeuristicElement(
+        start_state,
+        0,
+        neighborhood.heuristic(start, goal),
+    ));
+
+    while let Some(HeuristicElement(state, g, _)) = open.pop() {
+        let (pos, _, run) = state;
+        if pos == goal && run >= constraints.min_run {
+            let mut path = vec![pos];
+            let mut current = state;
+            while let Some(&prev) = came_from.get(&current) {
+                path.push(prev.0);
+                current = prev;
+            }
+            path.reverse();
+            return Some(Path::from_slice(&path, g));
+        }
+
+        if !closed.insert(state) {
+            continue;
+        }
+
+        let (pos, dir, run) = state;
+        neighbors.clear();
+        neighborhood.get_all_neighbors(pos, &mut neighbors);
+
+        for &next_pos in neighbors.iter() {
+            let cost = get_cost(next_pos);
+            if cost < 0 {
+                continue;
+            }
+
+            let step_dir = direction(pos, next_pos);
+            if dir.map_or(false, |d| is_reverse(d, step_dir)) {
+                continue;
+            }
+
+            let (next_run, turned) = match dir {
+                Some(d) if d == step_dir => (run + 1, false),
+                Some(_) if run < constraints.min_run => continue,
+                Some(_) => (1, true),
+                None => (1, false),
+            };
+            if next_run > constraints.max_run {
+                continue;
+            }
+
+            let next_state: State = (next_pos, Some(step_dir), next_run);
+            let tentative_g =
+                g + cost as usize + if turned { constraints.turn_cost } else { 0 };
+
+            if tentative_g < *g_score.get(&next_state).unwrap_or(&usize::MAX) {
+                g_score.insert(next_state, tentative_g);
+                came_from.insert(next_state, state);
+                let f = tentative_g + neighborhood.heuristic(next_pos, goal);
+                open.push(HeuristicElement(next_state, tentative_g, f));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neighbors::ManhattanNeighborhood;
+    use crate::path_cache::MovementConstraints;
+
+    #[test]
+    fn is_reverse_detects_opposite_directions_only() {
+        assert!(is_reverse((1, 0), (-1, 0)));
+        assert!(is_reverse((0, -1), (0, 1)));
+        assert!(!is_reverse((1, 0), (1, 0)));
+        assert!(!is_reverse((1, 0), (0, 1)));
+    }
+
+    #[test]
+    fn goal_is_only_accepted_once_min_run_is_satisfied() {
+        let (width, height) = (3, 3);
+        let neighborhood = ManhattanNeighborhood::new(width, height);
+        let constraints = MovementConstraints {
+            turn_cost: 0,
+            min_run: 2,
+            max_run: 100,
+        };
+
+        // (1, 1) can only be reached by turning after exactly one straight Tile, which `min_run
+        // = 2` forbids no matter which approach is tried
+        assert_eq!(
+            constrained_a_star_search(
+                &neighborhood,
+                |_| 1,
+                (0, 0),
+                (1, 1),
+                constraints,
+                width * height,
+            ),
+            None
+        );
+
+        // (2, 2) can be reached via two runs of exactly `min_run` Tiles each - right, right, then
+        // down, down - so the same constraints must still find it
+        let path = constrained_a_star_search(
+            &neighborhood,
+            |_| 1,
+            (0, 0),
+            (2, 2),
+            constraints,
+            width * height,
+        )
+        .expect("(2, 2) is reachable with two min_run-satisfying straight runs");
+        assert_eq!(path.cost(), 4);
+    }
+}
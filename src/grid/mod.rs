@@ -4,11 +4,14 @@ pub(crate) use a_star::a_star_search;
 mod dijkstra;
 pub(crate) use dijkstra::dijkstra_search;
 
+mod constrained;
+pub(crate) use constrained::constrained_a_star_search;
+
 use crate::path::{Cost, Path};
 
 use std::cmp::Ordering;
 
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub(crate) struct HeuristicElement<Id>(pub Id, pub Cost, pub Cost);
 impl<Id: Eq> PartialOrd for HeuristicElement<Id> {
     fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
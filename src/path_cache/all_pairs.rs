@@ -0,0 +1,112 @@
+use crate::{
+    graph::NodeList,
+    path::Cost,
+    NodeID, NodeIDMap,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Above this many abstract Nodes, [`PathCacheConfig::precompute_all_pairs`](super::cache_config::PathCacheConfig::precompute_all_pairs)
+/// is ignored: the `O(V^2)` distance/predecessor matrices would otherwise risk using more memory
+/// than the Node graph itself, for maps unusual enough to have this many Nodes.
+pub(super) const MAX_NODES: usize = 4096;
+
+/// All-pairs shortest distances (and predecessors, for reconstruction) between every abstract
+/// Node, computed once with Floyd-Warshall.
+///
+/// Trades `O(V^2)` memory and an `O(V^3)` build cost for near-constant-time path queries
+/// afterwards; see [`PathCacheConfig::precompute_all_pairs`](super::cache_config::PathCacheConfig::precompute_all_pairs).
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(super) struct AllPairs {
+    ids: Vec<NodeID>,
+    index: NodeIDMap<usize>,
+    dist: Vec<Vec<Cost>>,
+    pred: Vec<Vec<Option<usize>>>,
+}
+
+impl AllPairs {
+    /// Computes the all-pairs shortest distances over `nodes`, or `None` if there are more than
+    /// [`MAX_NODES`] of them.
+    pub(super) fn build(nodes: &NodeList) -> Option<AllPairs> {
+        let ids: Vec<NodeID> = nodes.keys().to_vec();
+        let n = ids.len();
+        if n > MAX_NODES {
+            return None;
+        }
+
+        let index: NodeIDMap<usize> = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+        let mut dist = vec![vec![Cost::MAX; n]; n];
+        let mut pred: Vec<Vec<Option<usize>>> = vec![vec![None; n]; n];
+        for i in 0..n {
+            dist[i][i] = 0;
+        }
+
+        for (i, &id) in ids.iter().enumerate() {
+            for (other_id, segment) in nodes[id].edges.iter() {
+                if let Some(&j) = index.get(other_id) {
+                    let cost = segment.cost();
+                    if cost < dist[i][j] {
+                        dist[i][j] = cost;
+                        pred[i][j] = Some(i);
+                    }
+                }
+            }
+        }
+
+        for k in 0..n {
+            for i in 0..n {
+                if dist[i][k] == Cost::MAX {
+                    continue;
+                }
+                for j in 0..n {
+                    if dist[k][j] == Cost::MAX {
+                        continue;
+                    }
+                    let through = dist[i][k] + dist[k][j];
+                    if through < dist[i][j] {
+                        dist[i][j] = through;
+                        pred[i][j] = pred[k][j];
+                    }
+                }
+            }
+        }
+
+        Some(AllPairs {
+            ids,
+            index,
+            dist,
+            pred,
+        })
+    }
+
+    /// The precomputed cost of the shortest Path from `start` to `goal`, if one exists.
+    pub(super) fn cost(&self, start: NodeID, goal: NodeID) -> Option<Cost> {
+        let &i = self.index.get(&start)?;
+        let &j = self.index.get(&goal)?;
+        let cost = self.dist[i][j];
+        (cost != Cost::MAX).then_some(cost)
+    }
+
+    /// Reconstructs the sequence of abstract Node IDs from `start` to `goal` by walking the
+    /// predecessor matrix backwards from `goal`.
+    pub(super) fn node_path(&self, start: NodeID, goal: NodeID) -> Option<Vec<NodeID>> {
+        let &start_index = self.index.get(&start)?;
+        let &goal_index = self.index.get(&goal)?;
+        if self.dist[start_index][goal_index] == Cost::MAX {
+            return None;
+        }
+
+        let mut indices = vec![goal_index];
+        let mut current = goal_index;
+        while current != start_index {
+            current = self.pred[start_index][current]?;
+            indices.push(current);
+        }
+        indices.reverse();
+
+        Some(indices.into_iter().map(|i| self.ids[i]).collect())
+    }
+}
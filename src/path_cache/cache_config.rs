@@ -0,0 +1,290 @@
+/// Configuration for creating a [`PathCache`](super::PathCache).
+///
+/// Use [`with_chunk_size`](PathCacheConfig::with_chunk_size) to create one, then adjust the
+/// other fields with the builder methods as needed.
+///
+/// ## Examples
+/// ```
+/// use hierarchical_pathfinding::prelude::*;
+///
+/// let config = PathCacheConfig::with_chunk_size(5).with_cache_paths(false);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct PathCacheConfig {
+    /// The size (width and height) of a Chunk.
+    ///
+    /// Smaller Chunks mean more, smaller Nodes, which speeds up the Node search within a Chunk,
+    /// but slows down the search across Chunks, since more Nodes have to be visited.
+    pub chunk_size: usize,
+    /// Whether to precompute and store the actual Paths between Nodes.
+    ///
+    /// If `true` (the default), the Path between two adjacent Nodes is calculated once, when the
+    /// Cache is built, and then cloned whenever it is needed.
+    ///
+    /// If `false`, only the Cost between two Nodes is stored, and the actual Path has to be
+    /// recalculated every time it is needed using [`safe_next`](crate::path::AbstractPath::safe_next).
+    /// This saves memory at the cost of extra computation during Pathfinding.
+    pub cache_paths: bool,
+    /// Whether to fall back to a plain Grid search for Paths that are short enough.
+    ///
+    /// If the resulting Path would be shorter than `2 * chunk_size`, it is usually faster to
+    /// just run a normal A* search on the Grid instead of going through the Node network.
+    pub a_star_fallback: bool,
+    /// Bounds the width of the open set used when searching the abstract Node graph.
+    ///
+    /// When `Some(width)`, the search only keeps the `width` most promising Nodes (by `f = g +
+    /// heuristic`) in its frontier after every expansion step, discarding the rest. This sacrifices
+    /// the guarantee that the returned Path is optimal in exchange for bounded memory usage and a
+    /// faster worst case on maps with huge Node graphs; the returned [`cost`](crate::path::Path::cost)
+    /// is then only an upper bound on the true cost.
+    ///
+    /// `None` (the default) performs a normal, unbounded search.
+    pub beam_width: Option<usize>,
+    /// Whether to precompute all-pairs shortest distances between abstract Nodes when building
+    /// the Cache.
+    ///
+    /// If `true`, an `O(V^3)` Floyd-Warshall pass runs once after the Node graph is connected,
+    /// storing an `O(V^2)` distance/predecessor matrix so that later calls to
+    /// [`find_path`](super::PathCache::find_path) can reconstruct the abstract Node sequence
+    /// instantly instead of running A* every time. This is a good tradeoff for maps with a
+    /// bounded number of Nodes and heavy query volume.
+    ///
+    /// Ignored (with no precomputation happening) once the Node count exceeds an internal
+    /// threshold, to avoid the `O(V^2)` memory use growing out of hand on huge maps.
+    ///
+    /// `false` by default.
+    pub precompute_all_pairs: bool,
+    /// Weights the heuristic in the abstract Node search, trading optimality for speed.
+    ///
+    /// The search used by [`find_path`](super::PathCache::find_path) normally orders its frontier
+    /// by `f = g + heuristic`. Setting this above `1.0` (ε) changes that to `f = g + ε·heuristic`,
+    /// which explores far fewer Nodes on large Node graphs at the cost of returning a Path that
+    /// may be up to ε times longer than optimal. This is the same bounded-suboptimal weighted A*
+    /// used by other game pathfinders (e.g. Veloren's astar).
+    ///
+    /// `1.0` (the default) behaves identically to an unweighted search.
+    ///
+    /// Only affects the abstract Node search in [`find_path`](super::PathCache::find_path); the
+    /// plain Grid `a_star_fallback` and the multi-goal search in
+    /// [`find_paths`](super::PathCache::find_paths) are unaffected.
+    pub heuristic_weight: f32,
+    /// Caps how many abstract Nodes [`find_path_partial`](super::PathCache::find_path_partial)
+    /// expands before giving up and returning its best effort so far.
+    ///
+    /// `None` (the default) means no cap; `find_path_partial` then only stops once it has either
+    /// reached the goal or exhausted the whole reachable frontier, same as
+    /// [`find_path`](super::PathCache::find_path) would.
+    ///
+    /// Does not affect `find_path` itself, which never returns a partial result.
+    pub max_expansions: Option<usize>,
+    /// Constrains how the plain Grid search may turn, forcing it to model agents (vehicles,
+    /// crawlers, ...) that can't change direction freely.
+    ///
+    /// When `Some`, every `grid_a_star` search augments its state with the incoming direction and
+    /// the number of consecutive Tiles travelled in it: continuing straight is free, but turning
+    /// is only allowed once [`min_run`](MovementConstraints::min_run) Tiles have been covered in
+    /// the current direction, costs [`turn_cost`](MovementConstraints::turn_cost) extra, and the
+    /// straight run may never exceed [`max_run`](MovementConstraints::max_run) Tiles.
+    ///
+    /// Because this changes what "optimal" means within a Chunk, setting this also disables the
+    /// abstract Node shortcut entirely: every Path is resolved with a full, constrained
+    /// `grid_a_star` search rather than stitched together from precomputed Node-to-Node Paths.
+    /// This sidesteps the trickiest part of carrying the constraint across Chunk borders (the
+    /// entry/exit Nodes of a stitched Path would otherwise need to record the run state they were
+    /// crossed with) at the cost of the abstract graph's speed advantage on long Paths.
+    ///
+    /// Reversing directly into the direction just come from is never allowed, and the goal Tile
+    /// is only accepted once `min_run` has been satisfied, same as any other Tile along the way.
+    ///
+    /// `None` (the default) performs a normal, unconstrained search.
+    pub movement_constraints: Option<MovementConstraints>,
+    /// Memoizes each Node-to-Node *query result* the first time [`find_path`](super::PathCache::find_path)
+    /// is asked for it, so that repeating the same start/goal Node pair later is an `O(1)` lookup
+    /// instead of a second search.
+    ///
+    /// This only caches what a query already found; it does not change what
+    /// [`connect_nodes`](super::PathCache) does during [`PathCache::new`](super::PathCache::new) -
+    /// adjacent-Node `PathSegment`s are still all computed eagerly up front, at the usual
+    /// construction cost, whether or not this is set. The first query between any two Nodes still
+    /// pays the full abstract-graph search cost; every later query between that same pair is then
+    /// an `O(1)` lookup.
+    ///
+    /// This is a deliberate re-scope from true lazy edge construction (deferring each adjacent
+    /// Node's `PathSegment` until its edge is first traversed): every edge `connect_nodes` builds
+    /// is already a trivial two-Point segment between Grid-adjacent Tiles, so there is no
+    /// expensive interior search to defer there. The only genuinely expensive interior Paths live
+    /// inside each Chunk's own construction, and deferring those would require `PathSegment`
+    /// itself to carry a "not yet computed" state - out of scope for this flag.
+    ///
+    /// Ignored if [`precompute_all_pairs`](PathCacheConfig::precompute_all_pairs) is also set,
+    /// since the eager matrix already answers every query in `O(1)`.
+    ///
+    /// While this is set, the search that `find_path` memoizes is always a plain A* search:
+    /// [`beam_width`](PathCacheConfig::beam_width),
+    /// [`heuristic_weight`](PathCacheConfig::heuristic_weight),
+    /// [`bidirectional_search`](PathCacheConfig::bidirectional_search),
+    /// [`ida_star_iterations`](PathCacheConfig::ida_star_iterations) and
+    /// [`use_contraction_hierarchy`](PathCacheConfig::use_contraction_hierarchy) are not used by it.
+    ///
+    /// `false` by default.
+    pub lazy_query_cache: bool,
+    /// Searches the abstract Node graph from both ends at once instead of only forward from
+    /// `start`.
+    ///
+    /// When `true`, [`find_path`](super::PathCache::find_path) alternates expanding a forward
+    /// search from the start Node and a backward search from the goal Node, picking whichever
+    /// frontier currently has the smaller top `f`-score, and keeps going after the two first meet
+    /// until neither frontier's top `f`-score is below the cheapest meeting cost found. This
+    /// roughly halves the number of Nodes expanded on long queries over large Node graphs compared
+    /// to a normal one-directional search, at the cost of maintaining two open sets instead of
+    /// one.
+    ///
+    /// `false` by default.
+    pub bidirectional_search: bool,
+    /// Runs [`find_path`](super::PathCache::find_path) as iterative-deepening A* (IDA*) instead of
+    /// a normal, open-set-based A* search, capped at this many deepening iterations.
+    ///
+    /// IDA* repeats a depth-first search bounded by a cost threshold, starting at
+    /// `heuristic(start, goal)` and growing to the smallest `f = g + heuristic` that exceeded the
+    /// previous threshold, until the goal is found. Since it only ever keeps the current DFS stack
+    /// in memory instead of an open set over the whole visited frontier, it trades (often
+    /// significant) re-expansion of the same Nodes across iterations for `O(depth)` memory use,
+    /// which matters once the abstract Node graph is too large for a normal search's open set and
+    /// visited maps to comfortably fit in memory.
+    ///
+    /// `Some(max_iterations)` gives up and returns `None` if the threshold still hasn't reached the
+    /// goal after that many deepening iterations, so a pathological map can't deepen forever;
+    /// `None` (the default) performs a normal, unbounded search instead of IDA*.
+    pub ida_star_iterations: Option<usize>,
+    /// Preprocesses the abstract Node graph into a Contraction Hierarchy when building the Cache,
+    /// so that [`find_path`](super::PathCache::find_path) can query it instead of running
+    /// whichever other search mode is configured. [`lazy_query_cache`](PathCacheConfig::lazy_query_cache)
+    /// takes precedence: with both set, the Contraction Hierarchy is still built, but `find_path`
+    /// does not query it.
+    ///
+    /// Every Node is contracted in turn (removed from a shrinking working copy of the graph,
+    /// inserting "shortcut" edges wherever that would otherwise lose a shortest connection between
+    /// two of its neighbors) and given a rank by contraction order; queries then only need to
+    /// search the much smaller "upward" graph of edges pointing towards higher ranks,
+    /// bidirectionally from both `start` and `goal`, before unpacking any shortcuts the winning
+    /// Path used back into real Nodes. This trades a more expensive one-time build (on top of the
+    /// Chunk/Node graph construction already done) for queries that expand far fewer Nodes.
+    ///
+    /// Ignored (with no Contraction Hierarchy built) if
+    /// [`precompute_all_pairs`](PathCacheConfig::precompute_all_pairs) is also set, since the eager
+    /// distance matrix already answers every query faster still.
+    ///
+    /// `false` by default.
+    pub use_contraction_hierarchy: bool,
+}
+
+/// Limits on how freely a Path may change direction; see
+/// [`PathCacheConfig::movement_constraints`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct MovementConstraints {
+    /// Extra cost added every time the Path changes direction.
+    pub turn_cost: usize,
+    /// The minimum number of Tiles that must be travelled in a straight line before turning.
+    pub min_run: usize,
+    /// The maximum number of Tiles that may be travelled in a straight line before a turn is
+    /// forced.
+    pub max_run: usize,
+}
+
+impl PathCacheConfig {
+    /// Creates a `PathCacheConfig` with the given `chunk_size` and default values for every
+    /// other field.
+    pub fn with_chunk_size(chunk_size: usize) -> Self {
+        PathCacheConfig {
+            chunk_size,
+            cache_paths: true,
+            a_star_fallback: true,
+            beam_width: None,
+            precompute_all_pairs: false,
+            heuristic_weight: 1.0,
+            max_expansions: None,
+            movement_constraints: None,
+            lazy_query_cache: false,
+            bidirectional_search: false,
+            ida_star_iterations: None,
+            use_contraction_hierarchy: false,
+        }
+    }
+
+    /// Sets [`cache_paths`](PathCacheConfig::cache_paths).
+    pub fn with_cache_paths(mut self, cache_paths: bool) -> Self {
+        self.cache_paths = cache_paths;
+        self
+    }
+
+    /// Sets [`a_star_fallback`](PathCacheConfig::a_star_fallback).
+    pub fn with_a_star_fallback(mut self, a_star_fallback: bool) -> Self {
+        self.a_star_fallback = a_star_fallback;
+        self
+    }
+
+    /// Sets [`beam_width`](PathCacheConfig::beam_width).
+    pub fn with_beam_width(mut self, beam_width: Option<usize>) -> Self {
+        self.beam_width = beam_width;
+        self
+    }
+
+    /// Sets [`precompute_all_pairs`](PathCacheConfig::precompute_all_pairs).
+    pub fn with_precompute_all_pairs(mut self, precompute_all_pairs: bool) -> Self {
+        self.precompute_all_pairs = precompute_all_pairs;
+        self
+    }
+
+    /// Sets [`heuristic_weight`](PathCacheConfig::heuristic_weight).
+    pub fn with_heuristic_weight(mut self, heuristic_weight: f32) -> Self {
+        self.heuristic_weight = heuristic_weight;
+        self
+    }
+
+    /// Sets [`max_expansions`](PathCacheConfig::max_expansions).
+    pub fn with_max_expansions(mut self, max_expansions: Option<usize>) -> Self {
+        self.max_expansions = max_expansions;
+        self
+    }
+
+    /// Sets [`movement_constraints`](PathCacheConfig::movement_constraints).
+    pub fn with_movement_constraints(
+        mut self,
+        movement_constraints: Option<MovementConstraints>,
+    ) -> Self {
+        self.movement_constraints = movement_constraints;
+        self
+    }
+
+    /// Sets [`lazy_query_cache`](PathCacheConfig::lazy_query_cache).
+    pub fn with_lazy_query_cache(mut self, lazy_query_cache: bool) -> Self {
+        self.lazy_query_cache = lazy_query_cache;
+        self
+    }
+
+    /// Sets [`bidirectional_search`](PathCacheConfig::bidirectional_search).
+    pub fn with_bidirectional_search(mut self, bidirectional_search: bool) -> Self {
+        self.bidirectional_search = bidirectional_search;
+        self
+    }
+
+    /// Sets [`ida_star_iterations`](PathCacheConfig::ida_star_iterations).
+    pub fn with_ida_star_iterations(mut self, ida_star_iterations: Option<usize>) -> Self {
+        self.ida_star_iterations = ida_star_iterations;
+        self
+    }
+
+    /// Sets [`use_contraction_hierarchy`](PathCacheConfig::use_contraction_hierarchy).
+    pub fn with_use_contraction_hierarchy(mut self, use_contraction_hierarchy: bool) -> Self {
+        self.use_contraction_hierarchy = use_contraction_hierarchy;
+        self
+    }
+}
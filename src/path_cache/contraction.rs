@@ -0,0 +1,290 @@
+use crate::{
+    graph::NodeList,
+    grid::Element,
+    path::{Cost, Path},
+    NodeID, NodeIDMap,
+};
+
+use std::collections::{BinaryHeap, HashMap};
+
+/// How many hops the witness search in [`ContractionHierarchy::build`] explores before giving up.
+///
+/// Missing a witness because of this cap only costs an unnecessary (but still correct) extra
+/// shortcut; it can never make a query return a wrong result.
+const WITNESS_HOP_LIMIT: usize = 5;
+
+/// A Contraction Hierarchy over the abstract Node graph, trading a one-time preprocessing pass for
+/// much faster repeat queries; see
+/// [`PathCacheConfig::use_contraction_hierarchy`](super::cache_config::PathCacheConfig::use_contraction_hierarchy).
+///
+/// Every Node is given a contraction rank, from first contracted (rank `0`) to last. Contracting a
+/// Node removes it from the working graph, adding a shortcut edge between any two of its remaining
+/// neighbors whose shortest connection ran through it. Only the "upward" direction of every edge
+/// (original or shortcut) is kept, stored under the lower-ranked endpoint: this `up_edges` graph is
+/// a fraction of the size of the original, and [`query`](ContractionHierarchy::query) only ever has
+/// to search it, bidirectionally, from both `start` and `goal` at once, meeting somewhere at the
+/// highest-ranked Node the real Path passes through. Shortcut edges are unpacked back into the base
+/// Node sequence they stand in for once a query's winning Path is found.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub(super) struct ContractionHierarchy {
+    order: NodeIDMap<usize>,
+    up_edges: NodeIDMap<Vec<(NodeID, Cost)>>,
+    shortcuts: HashMap<(NodeID, NodeID), NodeID>,
+}
+
+impl ContractionHierarchy {
+    /// Builds a `ContractionHierarchy` over every Node in `nodes`.
+    ///
+    /// Nodes are contracted in ascending order of their current degree in the shrinking working
+    /// graph: cheap to maintain, and a reasonable proxy for how few shortcuts contracting a Node
+    /// will need, without the expense of a proper priority-term (edge-difference) reordering.
+    pub(super) fn build(nodes: &NodeList) -> ContractionHierarchy {
+        let mut adj: NodeIDMap<NodeIDMap<Cost>> = NodeIDMap::default();
+        for (id, node) in nodes.iter() {
+            let neighbors: NodeIDMap<Cost> = node
+                .edges
+                .iter()
+                .map(|(&other, edge)| (other, edge.cost()))
+                .collect();
+            adj.insert(id, neighbors);
+        }
+
+        let mut remaining: Vec<NodeID> = nodes.keys().to_vec();
+        let mut order: NodeIDMap<usize> = NodeIDMap::default();
+        let mut shortcuts: HashMap<(NodeID, NodeID), NodeID> = HashMap::new();
+        let mut all_edges: Vec<(NodeID, NodeID, Cost)> = Vec::new();
+
+        for rank in 0..remaining.len() {
+            let (index, _) = remaining
+                .iter()
+                .enumerate()
+                .min_by_key(|&(_, id)| adj.get(id).map_or(0, NodeIDMap::len))
+                .expect("remaining is not empty");
+            let v = remaining.remove(index);
+
+            let neighbors: Vec<(NodeID, Cost)> = adj
+                .get(&v)
+                .expect("v still in graph")
+                .iter()
+                .map(|(&n, &c)| (n, c))
+                .collect();
+
+            for &(u, cost_uv) in &neighbors {
+                for &(w, cost_vw) in &neighbors {
+                    if u == w {
+                        continue;
+                    }
+                    let direct = cost_uv + cost_vw;
+                    let witness = witness_search(&adj, u, w, v, direct, WITNESS_HOP_LIMIT);
+                    let needs_shortcut = witness.map_or(true, |cost| cost > direct)
+                        && adj
+                            .get(&u)
+                            .and_then(|edges| edges.get(&w))
+                            .map_or(true, |&cost| direct < cost);
+                    if needs_shortcut {
+                        adj.get_mut(&u)
+                            .expect("u still in graph")
+                            .insert(w, direct);
+                        shortcuts.insert((u, w), v);
+                    }
+                }
+            }
+
+            for (&n, &cost) in adj.get(&v).expect("v still in graph").iter() {
+                all_edges.push((v, n, cost));
+                all_edges.push((n, v, cost));
+            }
+
+            let touched: Vec<NodeID> = adj.get(&v).expect("v still in graph").keys().copied().collect();
+            for n in touched {
+                adj.get_mut(&n).expect("n still in graph").remove(&v);
+            }
+            adj.remove(&v);
+
+            order.insert(v, rank);
+        }
+
+        let mut up_edges: NodeIDMap<Vec<(NodeID, Cost)>> = NodeIDMap::default();
+        for (a, b, cost) in all_edges {
+            if order.get(&a) < order.get(&b) {
+                let edges = up_edges.entry(a).or_default();
+                match edges.iter_mut().find(|(id, _)| *id == b) {
+                    Some((_, existing)) if *existing <= cost => {}
+                    Some(entry) => *entry = (b, cost),
+                    None => edges.push((b, cost)),
+                }
+            }
+        }
+
+        ContractionHierarchy {
+            order,
+            up_edges,
+            shortcuts,
+        }
+    }
+
+    /// Finds the shortest Path from `start` to `goal` with a bidirectional search of the upward
+    /// graph, unpacking any shortcut edges on the winning Path back into the base Node sequence
+    /// they stand in for.
+    pub(super) fn query(&self, start: NodeID, goal: NodeID) -> Option<Path<NodeID>> {
+        if start == goal {
+            return Some(Path::from_slice(&[start], 0));
+        }
+
+        let mut open_f: BinaryHeap<Element<NodeID>> = BinaryHeap::new();
+        let mut open_b: BinaryHeap<Element<NodeID>> = BinaryHeap::new();
+        let mut g_f: NodeIDMap<Cost> = NodeIDMap::default();
+        let mut g_b: NodeIDMap<Cost> = NodeIDMap::default();
+        let mut came_from_f: NodeIDMap<NodeID> = NodeIDMap::default();
+        let mut came_from_b: NodeIDMap<NodeID> = NodeIDMap::default();
+
+        g_f.insert(start, 0usize);
+        g_b.insert(goal, 0usize);
+        open_f.push(Element(start, 0));
+        open_b.push(Element(goal, 0));
+
+        let mut best: Option<(Cost, NodeID)> = None;
+
+        loop {
+            let top_f = open_f.peek().map(|elem| elem.1);
+            let top_b = open_b.peek().map(|elem| elem.1);
+
+            let Some(lower_bound) = top_f.into_iter().chain(top_b).min() else {
+                break;
+            };
+            if best.map_or(false, |(mu, _)| lower_bound >= mu) {
+                break;
+            }
+
+            let expand_forward = match (top_f, top_b) {
+                (Some(f), Some(b)) => f <= b,
+                (Some(_), None) => true,
+                (None, _) => false,
+            };
+
+            if expand_forward {
+                let Element(current, g) = open_f.pop().expect("top_f was Some");
+                if let Some(&g_other) = g_b.get(&current) {
+                    let total = g + g_other;
+                    if best.map_or(true, |(mu, _)| total < mu) {
+                        best = Some((total, current));
+                    }
+                }
+                for &(neighbor, cost) in self.up_edges.get(&current).map_or(&[][..], Vec::as_slice) {
+                    let tentative = g + cost;
+                    if tentative < *g_f.get(&neighbor).unwrap_or(&usize::MAX) {
+                        g_f.insert(neighbor, tentative);
+                        came_from_f.insert(neighbor, current);
+                        open_f.push(Element(neighbor, tentative));
+                    }
+                }
+            } else {
+                let Element(current, g) = open_b.pop().expect("top_b was Some");
+                if let Some(&g_other) = g_f.get(&current) {
+                    let total = g + g_other;
+                    if best.map_or(true, |(mu, _)| total < mu) {
+                        best = Some((total, current));
+                    }
+                }
+                for &(neighbor, cost) in self.up_edges.get(&current).map_or(&[][..], Vec::as_slice) {
+                    let tentative = g + cost;
+                    if tentative < *g_b.get(&neighbor).unwrap_or(&usize::MAX) {
+                        g_b.insert(neighbor, tentative);
+                        came_from_b.insert(neighbor, current);
+                        open_b.push(Element(neighbor, tentative));
+                    }
+                }
+            }
+        }
+
+        let (cost, meeting) = best?;
+
+        let mut up_path = vec![meeting];
+        let mut node = meeting;
+        while let Some(&prev) = came_from_f.get(&node) {
+            up_path.push(prev);
+            node = prev;
+        }
+        up_path.reverse();
+
+        let mut node = meeting;
+        while let Some(&next) = came_from_b.get(&node) {
+            up_path.push(next);
+            node = next;
+        }
+
+        let mut path = vec![up_path[0]];
+        for pair in up_path.windows(2) {
+            self.expand_edge(pair[0], pair[1], &mut path);
+        }
+
+        Some(Path::from_slice(&path, cost))
+    }
+
+    /// Recursively unpacks the shortcut edge `(a, b)`, if any, pushing every base-graph Node
+    /// between them (and finally `b` itself) onto `out`. `a` must already be the last Node in
+    /// `out`.
+    fn expand_edge(&self, a: NodeID, b: NodeID, out: &mut Vec<NodeID>) {
+        if let Some(&mid) = self.shortcuts.get(&(a, b)) {
+            self.expand_edge(a, mid, out);
+            self.expand_edge(mid, b, out);
+        } else {
+            out.push(b);
+        }
+    }
+}
+
+/// Dijkstra from `u` to `w` over the working graph `adj`, skipping `avoid` entirely, giving up
+/// early past `limit` total cost or [`WITNESS_HOP_LIMIT`]-equivalent `hop_limit` hops.
+///
+/// Used as the witness search during [`ContractionHierarchy::build`]: if this doesn't find a Path
+/// cheaper than `limit`, a shortcut through `avoid` is needed to preserve `u`'s connection to `w`.
+fn witness_search(
+    adj: &NodeIDMap<NodeIDMap<Cost>>,
+    u: NodeID,
+    w: NodeID,
+    avoid: NodeID,
+    limit: Cost,
+    hop_limit: usize,
+) -> Option<Cost> {
+    let mut open: BinaryHeap<Element<(NodeID, usize)>> = BinaryHeap::new();
+    let mut best: NodeIDMap<Cost> = NodeIDMap::default();
+
+    best.insert(u, 0);
+    open.push(Element((u, 0), 0));
+
+    while let Some(Element((current, hops), g)) = open.pop() {
+        if g > limit {
+            break;
+        }
+        if current == w {
+            return Some(g);
+        }
+        if g > *best.get(&current).unwrap_or(&usize::MAX) {
+            continue;
+        }
+        if hops >= hop_limit {
+            continue;
+        }
+
+        let Some(edges) = adj.get(&current) else {
+            continue;
+        };
+        for (&neighbor, &cost) in edges.iter() {
+            if neighbor == avoid {
+                continue;
+            }
+            let tentative = g + cost;
+            if tentative <= limit && tentative < *best.get(&neighbor).unwrap_or(&usize::MAX) {
+                best.insert(neighbor, tentative);
+                open.push(Element((neighbor, hops + 1), tentative));
+            }
+        }
+    }
+
+    None
+}
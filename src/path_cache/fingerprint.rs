@@ -0,0 +1,28 @@
+use crate::Point;
+
+/// Computes a cheap 64-bit rolling hash of a cost grid.
+///
+/// This is used to validate a previously-serialized [`PathCache`](super::PathCache) against the
+/// Grid it is being loaded for: if the grid changed since the Cache was saved, the fingerprint
+/// will (almost always) differ and the stale Cache can be rejected instead of silently producing
+/// wrong Paths.
+///
+/// The hash is a simple FNV-1a style fold over every Tile in row-major order, which is fast
+/// enough to run over the whole Grid once at load time without noticeably adding to startup cost.
+pub(crate) fn hash_grid(
+    (width, height): (usize, usize),
+    mut get_cost: impl FnMut(Point) -> isize,
+) -> u64 {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = FNV_OFFSET;
+    for y in 0..height {
+        for x in 0..width {
+            let cost = get_cost((x, y));
+            hash ^= cost as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+    hash
+}
@@ -0,0 +1,163 @@
+use crate::{
+    grid::Element,
+    graph::NodeList,
+    path::Path,
+    NodeID, NodeIDMap, NodeIDSet,
+};
+
+use std::collections::{BinaryHeap, HashSet};
+
+/// The total cost of walking `seq` edge by edge over `nodes`.
+fn path_cost(nodes: &NodeList, seq: &[NodeID]) -> usize {
+    seq.windows(2)
+        .map(|pair| nodes[pair[0]].edges[&pair[1]].cost())
+        .sum()
+}
+
+/// Plain Dijkstra over the abstract Node graph from `start` to `goal`, refusing to step onto any
+/// Node in `banned_nodes` or to take any edge in `banned_edges`.
+///
+/// This is the "spur path" search that [`k_shortest_paths`] runs from every Node of the previous
+/// shortest Path in turn; banning the prefix already used by earlier Paths and the edge they took
+/// out of the spur Node is what keeps every found Path distinct.
+fn restricted_dijkstra(
+    nodes: &NodeList,
+    start: NodeID,
+    goal: NodeID,
+    banned_nodes: &NodeIDSet,
+    banned_edges: &HashSet<(NodeID, NodeID)>,
+) -> Option<Path<NodeID>> {
+    let mut open: BinaryHeap<Element<NodeID>> = BinaryHeap::new();
+    let mut came_from = NodeIDMap::default();
+    let mut g_score = NodeIDMap::default();
+    let mut closed = NodeIDSet::default();
+
+    g_score.insert(start, 0usize);
+    open.push(Element(start, 0));
+
+    while let Some(Element(current, g)) = open.pop() {
+        if current == goal {
+            let mut path = vec![current];
+            let mut node = current;
+            while let Some(&prev) = came_from.get(&node) {
+                path.push(prev);
+                node = prev;
+            }
+            path.reverse();
+            return Some(Path::from_slice(&path, g));
+        }
+
+        if !closed.insert(current) {
+            continue;
+        }
+
+        for (&neighbor, edge) in nodes[current].edges.iter() {
+            if closed.contains(&neighbor) {
+                continue;
+            }
+            if neighbor != goal && banned_nodes.contains(&neighbor) {
+                continue;
+            }
+            if banned_edges.contains(&(current, neighbor)) {
+                continue;
+            }
+            let tentative_g = g + edge.cost();
+            if tentative_g < *g_score.get(&neighbor).unwrap_or(&usize::MAX) {
+                g_score.insert(neighbor, tentative_g);
+                came_from.insert(neighbor, current);
+                open.push(Element(neighbor, tentative_g));
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds up to `k` loopless Paths from `start` to `goal` over the abstract Node graph, in
+/// increasing cost order, using Yen's algorithm.
+///
+/// The first Path is the plain shortest one. Every later Path is found by taking each Node of the
+/// previous Path in turn as a "spur Node": the prefix leading up to it (the "root Path") is kept,
+/// every edge that any already-found Path took out of an equal prefix is banned so the search
+/// can't just retrace a Path already returned, and [`restricted_dijkstra`] finds the cheapest way
+/// from the spur Node to `goal` under that restriction. Splicing the root Path onto the result
+/// gives a candidate, which is pushed onto a min-heap (`B`) of not-yet-returned candidates; the
+/// cheapest not already returned is popped as the next Path.
+///
+/// Stops early, with fewer than `k` Paths, once `B` runs dry before `k` is reached.
+pub(super) fn k_shortest_paths(
+    nodes: &NodeList,
+    start: NodeID,
+    goal: NodeID,
+    k: usize,
+) -> Vec<Path<NodeID>> {
+    if k == 0 {
+        return vec![];
+    }
+
+    let Some(first) = restricted_dijkstra(nodes, start, goal, &NodeIDSet::default(), &HashSet::new())
+    else {
+        return vec![];
+    };
+
+    let mut found: Vec<Vec<NodeID>> = vec![first.iter().copied().to_vec()];
+    let mut costs: Vec<usize> = vec![first.cost()];
+
+    let mut seen: HashSet<Vec<NodeID>> = HashSet::new();
+    seen.insert(found[0].clone());
+
+    // candidates not yet returned, keyed by index into `candidate_seqs` so the heap (ordered by
+    // cost, reusing `Element` like the rest of the abstract Node searches) never has to compare
+    // the sequences themselves
+    let mut candidate_seqs: Vec<Vec<NodeID>> = Vec::new();
+    let mut heap: BinaryHeap<Element<usize>> = BinaryHeap::new();
+
+    while found.len() < k {
+        let prev = found.last().expect("found is never empty").clone();
+
+        for i in 0..prev.len().saturating_sub(1) {
+            let spur_node = prev[i];
+            let root_path = &prev[..=i];
+
+            let mut banned_edges = HashSet::new();
+            for path in &found {
+                if path.len() > i + 1 && path[..=i] == *root_path {
+                    banned_edges.insert((path[i], path[i + 1]));
+                }
+            }
+            let banned_nodes: NodeIDSet = root_path[..i].iter().copied().collect();
+
+            let Some(spur_path) =
+                restricted_dijkstra(nodes, spur_node, goal, &banned_nodes, &banned_edges)
+            else {
+                continue;
+            };
+
+            let mut total = root_path[..i].to_vec();
+            total.extend(spur_path.iter().copied());
+
+            if seen.contains(&total) || candidate_seqs.contains(&total) {
+                continue;
+            }
+
+            let cost = path_cost(nodes, &total);
+            let index = candidate_seqs.len();
+            candidate_seqs.push(total);
+            heap.push(Element(index, cost));
+        }
+
+        let Some(Element(index, cost)) = heap.pop() else {
+            break;
+        };
+        let chosen = candidate_seqs[index].clone();
+        seen.insert(chosen.clone());
+        found.push(chosen);
+        costs.push(cost);
+    }
+
+    found
+        .into_iter()
+        .zip(costs)
+        .map(|(seq, cost)| Path::from_slice(&seq, cost))
+        .collect()
+}
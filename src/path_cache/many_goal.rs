@@ -0,0 +1,81 @@
+use crate::{
+    graph::NodeList,
+    grid::Element,
+    path::{Cost, Path},
+    NodeID, NodeIDMap, NodeIDSet,
+};
+
+use std::collections::BinaryHeap;
+
+/// Runs a single Dijkstra sweep from `start` over the abstract Node graph, settling the Cost and
+/// Path to each Node in `goals` as the sweep pops it, instead of searching once per goal.
+///
+/// Stops early once every requested goal has been settled, or once the frontier's minimum Cost
+/// exceeds `budget` (if given); far-away or unreachable goals then simply get no entry. This is
+/// the natural building block for any one-to-many query - populating the hierarchical cache,
+/// ranking goals by distance, or (as [`PathCache::find_costs_to_many`](super::PathCache::find_costs_to_many)
+/// does) reading off just the Cost half of the result - since all of the goals share the one
+/// sweep.
+pub(super) fn dijkstra_to_many(
+    nodes: &NodeList,
+    start: NodeID,
+    goals: &NodeIDSet,
+    budget: Option<Cost>,
+) -> NodeIDMap<(Cost, Path<NodeID>)> {
+    let mut open: BinaryHeap<Element<NodeID>> = BinaryHeap::new();
+    let mut g_score: NodeIDMap<Cost> = NodeIDMap::default();
+    let mut came_from: NodeIDMap<NodeID> = NodeIDMap::default();
+    let mut closed = NodeIDSet::default();
+    let mut settled: NodeIDMap<(Cost, Path<NodeID>)> = NodeIDMap::default();
+    let mut remaining: NodeIDSet = goals.clone();
+
+    if remaining.remove(&start) {
+        settled.insert(start, (0, Path::from_slice(&[start], 0)));
+    }
+
+    g_score.insert(start, 0usize);
+    open.push(Element(start, 0));
+
+    while !remaining.is_empty() {
+        let Some(Element(current, g)) = open.pop() else {
+            break;
+        };
+        if budget.map_or(false, |budget| g > budget) {
+            break;
+        }
+        if !closed.insert(current) {
+            continue;
+        }
+
+        if remaining.remove(&current) {
+            settled.insert(current, (g, reconstruct(&came_from, current, g)));
+        }
+
+        for (&neighbor, edge) in nodes[current].edges.iter() {
+            if closed.contains(&neighbor) {
+                continue;
+            }
+            let tentative_g = g + edge.cost();
+            if tentative_g < *g_score.get(&neighbor).unwrap_or(&usize::MAX) {
+                g_score.insert(neighbor, tentative_g);
+                came_from.insert(neighbor, current);
+                open.push(Element(neighbor, tentative_g));
+            }
+        }
+    }
+
+    settled
+}
+
+/// Walks `came_from` back from `goal` to the sweep's start, producing the Path the sweep
+/// actually settled on.
+fn reconstruct(came_from: &NodeIDMap<NodeID>, goal: NodeID, cost: Cost) -> Path<NodeID> {
+    let mut path = vec![goal];
+    let mut node = goal;
+    while let Some(&prev) = came_from.get(&node) {
+        path.push(prev);
+        node = prev;
+    }
+    path.reverse();
+    Path::from_slice(&path, cost)
+}
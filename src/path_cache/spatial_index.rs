@@ -0,0 +1,69 @@
+use crate::{graph::NodeList, NodeID, Point};
+
+use rstar::{primitives::GeomWithData, RTree};
+
+/// How many of the globally nearest Nodes [`SpatialIndex::nearest_nodes`] yields before giving
+/// up; each candidate costs a full `grid_a_star` trial in
+/// [`PathCache::find_nearest_node`](super::PathCache::find_nearest_node), so this is kept small.
+const MAX_FALLBACK_CANDIDATES: usize = 8;
+
+type IndexedNode = GeomWithData<[f32; 2], NodeID>;
+
+fn coords(pos: Point) -> [f32; 2] {
+    [pos.0 as f32, pos.1 as f32]
+}
+
+/// An R-tree over every abstract Node's position, kept roughly in sync with `self.nodes`.
+///
+/// `find_nearest_node`'s normal lookup only searches within one Chunk, which misses reachable
+/// Nodes across a Chunk border when a Point sits in a "cave" disconnected from its own Chunk's
+/// Node network. This index lets that case fall back to a sublinear, global nearest-Nodes query
+/// instead of giving up.
+#[derive(Clone, Debug)]
+pub(super) struct SpatialIndex {
+    tree: RTree<IndexedNode>,
+}
+
+impl Default for SpatialIndex {
+    fn default() -> Self {
+        SpatialIndex { tree: RTree::new() }
+    }
+}
+
+impl SpatialIndex {
+    /// Rebuilds the index from scratch over every Node currently in `nodes`.
+    pub(super) fn build(nodes: &NodeList) -> SpatialIndex {
+        SpatialIndex {
+            tree: RTree::bulk_load(
+                nodes
+                    .iter()
+                    .map(|(id, node)| IndexedNode::new(coords(node.pos), id))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// The Node IDs nearest to `pos`, closest first, capped at [`MAX_FALLBACK_CANDIDATES`].
+    pub(super) fn nearest_nodes(&self, pos: Point) -> impl Iterator<Item = NodeID> + '_ {
+        self.tree
+            .nearest_neighbor_iter(&coords(pos))
+            .map(|indexed| indexed.data)
+            .take(MAX_FALLBACK_CANDIDATES)
+    }
+
+    /// Adds a single Node to the index in `O(log V)`, without touching the rest of the tree.
+    ///
+    /// Used to keep the index in sync with a handful of Node additions (e.g. from
+    /// `tiles_changed`) without paying for a full [`build`](SpatialIndex::build).
+    pub(super) fn insert(&mut self, id: NodeID, pos: Point) {
+        self.tree.insert(IndexedNode::new(coords(pos), id));
+    }
+
+    /// Removes a single Node from the index in `O(log V)`, without touching the rest of the tree.
+    ///
+    /// `pos` must be the Node's position at the time it was [`insert`](SpatialIndex::insert)ed or
+    /// [`build`](SpatialIndex::build)t, since the R-tree looks entries up by geometry.
+    pub(super) fn remove(&mut self, id: NodeID, pos: Point) {
+        self.tree.remove(&IndexedNode::new(coords(pos), id));
+    }
+}
@@ -0,0 +1,212 @@
+use crate::path::Cost;
+
+/// Fills in the Held-Karp dp/parent tables shared by [`best_order_held_karp`] and
+/// [`best_order_held_karp_to`]: `dp[mask][j]` holds the cheapest cost of a path that starts at
+/// node `0` (not itself part of `indices`), visits exactly the waypoints in `mask`, and ends at
+/// waypoint `j`, built up from `dp[mask \ {j}][k] + leg_cost(k, j)` for every `k` still in `mask`.
+fn held_karp_tables(
+    indices: &[usize],
+    leg_cost: &impl Fn(usize, usize) -> Option<Cost>,
+) -> (Vec<Vec<Option<Cost>>>, Vec<Vec<Option<usize>>>) {
+    let k = indices.len();
+    let mut dp = vec![vec![None::<Cost>; k]; 1 << k];
+    let mut parent = vec![vec![None::<usize>; k]; 1 << k];
+
+    for (j, &point) in indices.iter().enumerate() {
+        if let Some(cost) = leg_cost(0, point) {
+            dp[1 << j][j] = Some(cost);
+        }
+    }
+
+    let full_mask = (1usize << k) - 1;
+    for mask in 1..=full_mask {
+        for j in 0..k {
+            if mask & (1 << j) == 0 {
+                continue;
+            }
+            let Some(cost_so_far) = dp[mask][j] else {
+                continue;
+            };
+            for next in 0..k {
+                if mask & (1 << next) != 0 {
+                    continue;
+                }
+                let Some(step) = leg_cost(indices[j], indices[next]) else {
+                    continue;
+                };
+                let next_mask = mask | (1 << next);
+                let candidate = cost_so_far + step;
+                if dp[next_mask][next].map_or(true, |best| candidate < best) {
+                    dp[next_mask][next] = Some(candidate);
+                    parent[next_mask][next] = Some(j);
+                }
+            }
+        }
+    }
+
+    (dp, parent)
+}
+
+/// Walks `parent` back from `(full_mask, j)` to recover the order of `indices` that dp table
+/// describes, reversing it back into visit order.
+fn reconstruct(
+    indices: &[usize],
+    parent: &[Vec<Option<usize>>],
+    full_mask: usize,
+    mut j: usize,
+) -> Vec<usize> {
+    let mut order = Vec::with_capacity(indices.len());
+    let mut mask = full_mask;
+    loop {
+        order.push(indices[j]);
+        match parent[mask][j] {
+            Some(prev) => {
+                mask &= !(1 << j);
+                j = prev;
+            }
+            None => break,
+        }
+    }
+    order.reverse();
+    order
+}
+
+/// Finds the cheapest ordering of `indices` via the Held-Karp dynamic program, starting from
+/// node `0` (not itself part of `indices`).
+///
+/// `leg_cost(i, j)` must return the cost of going from node `i` directly to node `j`, or `None`
+/// if they aren't connected.
+///
+/// This is exact, unlike [`best_order_heuristic`], but its `O(2^n * n^2)` cost still grows fast
+/// enough that it's only intended for a moderate number of waypoints (roughly `<= 20`).
+pub(super) fn best_order_held_karp(
+    indices: &[usize],
+    leg_cost: &impl Fn(usize, usize) -> Option<Cost>,
+) -> Option<Vec<usize>> {
+    let k = indices.len();
+    if k == 0 {
+        return Some(vec![]);
+    }
+
+    let full_mask = (1usize << k) - 1;
+    let (dp, parent) = held_karp_tables(indices, leg_cost);
+
+    let (_, j) = (0..k)
+        .filter_map(|j| dp[full_mask][j].map(|cost| (cost, j)))
+        .min_by_key(|&(cost, _)| cost)?;
+
+    Some(reconstruct(indices, &parent, full_mask, j))
+}
+
+/// Like [`best_order_held_karp`], but the tour must end at `end` (a node not itself part of
+/// `indices`) rather than wherever turns out cheapest, so that the final leg `indices[last] ->
+/// end` is taken into account when choosing the order.
+///
+/// Used by [`find_path_waypoints`](super::PathCache::find_path_waypoints) to order waypoints that
+/// lie between a fixed `start` and a fixed final `goal`.
+pub(super) fn best_order_held_karp_to(
+    indices: &[usize],
+    end: usize,
+    leg_cost: &impl Fn(usize, usize) -> Option<Cost>,
+) -> Option<Vec<usize>> {
+    let k = indices.len();
+    let full_mask = (1usize << k) - 1;
+    let (dp, parent) = held_karp_tables(indices, leg_cost);
+
+    let (_, j) = (0..k)
+        .filter_map(|j| {
+            let cost = dp[full_mask][j]? + leg_cost(indices[j], end)?;
+            Some((cost, j))
+        })
+        .min_by_key(|&(cost, _)| cost)?;
+
+    Some(reconstruct(indices, &parent, full_mask, j))
+}
+
+/// Builds a nearest-neighbor tour over `indices` starting from node `0`, then improves it with
+/// 2-opt swaps until no swap helps anymore.
+///
+/// Used instead of [`best_order_held_karp`] once there are too many waypoints to run the dynamic
+/// program; not guaranteed to find the optimal order.
+pub(super) fn best_order_heuristic(
+    indices: &[usize],
+    leg_cost: &impl Fn(usize, usize) -> Option<Cost>,
+) -> Option<Vec<usize>> {
+    let mut remaining: Vec<usize> = indices.to_vec();
+    let mut order = Vec::with_capacity(remaining.len());
+    let mut current = 0;
+
+    while !remaining.is_empty() {
+        let (pos, _) = remaining
+            .iter()
+            .enumerate()
+            .filter_map(|(pos, &candidate)| leg_cost(current, candidate).map(|cost| (pos, cost)))
+            .min_by_key(|&(_, cost)| cost)?;
+        let chosen = remaining.remove(pos);
+        order.push(chosen);
+        current = chosen;
+    }
+
+    two_opt(&mut order, None, leg_cost);
+    Some(order)
+}
+
+/// Like [`best_order_heuristic`], but the tour must end at `end` rather than wherever the
+/// nearest-neighbor walk happens to finish.
+///
+/// Used by [`find_path_waypoints`](super::PathCache::find_path_waypoints) once there are too many
+/// waypoints for [`best_order_held_karp_to`].
+pub(super) fn best_order_heuristic_to(
+    indices: &[usize],
+    end: usize,
+    leg_cost: &impl Fn(usize, usize) -> Option<Cost>,
+) -> Option<Vec<usize>> {
+    let mut order = best_order_heuristic(indices, leg_cost)?;
+    two_opt(&mut order, Some(end), leg_cost);
+    Some(order)
+}
+
+fn two_opt(
+    order: &mut Vec<usize>,
+    end: Option<usize>,
+    leg_cost: &impl Fn(usize, usize) -> Option<Cost>,
+) {
+    let mut improved = true;
+    while improved {
+        improved = false;
+        let Some(mut best_cost) = tour_cost(0, order, end, leg_cost) else {
+            return;
+        };
+        for i in 0..order.len() {
+            for j in (i + 1)..order.len() {
+                let mut candidate = order.clone();
+                candidate[i..=j].reverse();
+                if let Some(candidate_cost) = tour_cost(0, &candidate, end, leg_cost) {
+                    if candidate_cost < best_cost {
+                        *order = candidate;
+                        best_cost = candidate_cost;
+                        improved = true;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn tour_cost(
+    start: usize,
+    order: &[usize],
+    end: Option<usize>,
+    leg_cost: &impl Fn(usize, usize) -> Option<Cost>,
+) -> Option<Cost> {
+    let mut prev = start;
+    let mut total = 0;
+    for &next in order {
+        total += leg_cost(prev, next)?;
+        prev = next;
+    }
+    if let Some(end) = end {
+        total += leg_cost(prev, end)?;
+    }
+    Some(total)
+}